@@ -0,0 +1,106 @@
+use std::env;
+use std::fs;
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+
+fn emit(key: &str, value: impl AsRef<str>) {
+	println!("cargo:rustc-env=MCPGW_BUILD_{key}={}", value.as_ref());
+}
+
+fn git_output(args: &[&str]) -> Option<String> {
+	Command::new("git")
+		.args(args)
+		.output()
+		.ok()
+		.filter(|o| o.status.success())
+		.map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+}
+
+// `format_rfc3339_utc` and `rust_channel` live in `src/build_support.rs` so they're compiled
+// (and unit-tested) as part of the crate itself, not just this build script.
+include!("src/build_support.rs");
+
+fn main() {
+	println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+	println!("cargo:rerun-if-changed=../../.git/HEAD");
+
+	emit("buildVersion", env::var("CARGO_PKG_VERSION").unwrap_or_default());
+	emit(
+		"buildGitRevision",
+		git_output(&["rev-parse", "HEAD"]).unwrap_or_else(|| "unknown".to_string()),
+	);
+	emit("buildStatus", "release");
+	emit(
+		"buildTag",
+		git_output(&["describe", "--tags", "--always"]).unwrap_or_else(|| "unknown".to_string()),
+	);
+	emit("RUSTC_VERSION", rustc_version());
+	emit("PROFILE_NAME", env::var("PROFILE").unwrap_or_default());
+
+	// Default to `false` (not clean) when git can't be queried at all (no `git` binary, not a
+	// checkout, etc.) rather than claiming cleanliness we have no evidence for.
+	let git_clean = git_output(&["status", "--porcelain"])
+		.map(|s| s.is_empty())
+		.unwrap_or(false);
+	emit("GIT_CLEAN", git_clean.to_string());
+	emit(
+		"GIT_BRANCH",
+		git_output(&["rev-parse", "--abbrev-ref", "HEAD"]).unwrap_or_else(|| "unknown".to_string()),
+	);
+	emit(
+		"GIT_COMMIT_SHORT",
+		git_output(&["rev-parse", "--short", "HEAD"]).unwrap_or_else(|| "unknown".to_string()),
+	);
+	emit("FEATURES", enabled_features());
+	emit("TARGET", env::var("TARGET").unwrap_or_default());
+	emit("HOST", env::var("HOST").unwrap_or_default());
+	emit("RUST_CHANNEL", rust_channel(&rustc_version()));
+
+	println!("cargo:rerun-if-env-changed=MCPGW_CAPTURE_DEPENDENCIES");
+	let dependencies = if env::var("MCPGW_CAPTURE_DEPENDENCIES").as_deref() == Ok("1") {
+		Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+			.arg("tree")
+			.output()
+			.ok()
+			.filter(|o| o.status.success())
+			.map(|o| String::from_utf8_lossy(&o.stdout).into_owned())
+			.unwrap_or_default()
+	} else {
+		String::new()
+	};
+	let out_dir = env::var("OUT_DIR").expect("OUT_DIR set by cargo");
+	fs::write(format!("{out_dir}/dependencies.txt"), dependencies)
+		.expect("failed to write dependencies snapshot");
+
+	let unix_secs = match env::var("SOURCE_DATE_EPOCH") {
+		Ok(epoch) => epoch
+			.parse::<i64>()
+			.expect("SOURCE_DATE_EPOCH must be a Unix timestamp"),
+		Err(_) => SystemTime::now()
+			.duration_since(SystemTime::UNIX_EPOCH)
+			.unwrap_or(Duration::ZERO)
+			.as_secs() as i64,
+	};
+	emit("TIMESTAMP", format_rfc3339_utc(unix_secs));
+}
+
+/// Collects the `CARGO_FEATURE_*` env vars cargo exposes to build scripts, normalized to the
+/// hyphenated form used in `Cargo.toml` (e.g. `CARGO_FEATURE_FOO_BAR` -> `foo-bar`).
+fn enabled_features() -> String {
+	let mut features: Vec<String> = env::vars()
+		.filter_map(|(key, _)| key.strip_prefix("CARGO_FEATURE_").map(str::to_string))
+		.map(|name| name.to_lowercase().replace('_', "-"))
+		.collect();
+	features.sort();
+	features.join(",")
+}
+
+fn rustc_version() -> String {
+	Command::new(env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string()))
+		.arg("--version")
+		.output()
+		.ok()
+		.filter(|o| o.status.success())
+		.map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+		.unwrap_or_else(|| "unknown".to_string())
+}
@@ -0,0 +1,125 @@
+// Pure helpers shared between `build.rs` (via `include!`) and the crate itself, so the
+// build-script-only logic still gets exercised by `cargo test`. Plain comments rather than
+// `//!` here: `include!` rejects inner doc comments in the middle of `build.rs`.
+
+/// Formats a Unix timestamp as an RFC 3339 UTC instant without pulling in a chrono dependency.
+#[allow(dead_code)]
+fn format_rfc3339_utc(unix_secs: i64) -> String {
+	let days_since_epoch = unix_secs.div_euclid(86_400);
+	let secs_of_day = unix_secs.rem_euclid(86_400);
+
+	// Civil-from-days algorithm (Howard Hinnant's `civil_from_days`).
+	let z = days_since_epoch + 719_468;
+	let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+	let doe = (z - era * 146_097) as i64;
+	let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+	let y = yoe + era * 400;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = doy - (153 * mp + 2) / 5 + 1;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+	let year = if month <= 2 { y + 1 } else { y };
+
+	let hour = secs_of_day / 3600;
+	let minute = (secs_of_day % 3600) / 60;
+	let second = secs_of_day % 60;
+
+	format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}Z")
+}
+
+#[cfg(test)]
+mod format_rfc3339_utc_tests {
+	use super::format_rfc3339_utc;
+
+	#[test]
+	fn epoch() {
+		assert_eq!(format_rfc3339_utc(0), "1970-01-01T00:00:00Z");
+	}
+
+	#[test]
+	fn before_epoch() {
+		assert_eq!(format_rfc3339_utc(-1), "1969-12-31T23:59:59Z");
+	}
+
+	#[test]
+	fn leap_day() {
+		// 2024-02-29T12:34:56Z
+		assert_eq!(format_rfc3339_utc(1_709_210_096), "2024-02-29T12:34:56Z");
+	}
+
+	#[test]
+	fn non_leap_year_end_of_february() {
+		// 2023-02-28T00:00:00Z
+		assert_eq!(format_rfc3339_utc(1_677_542_400), "2023-02-28T00:00:00Z");
+	}
+
+	#[test]
+	fn century_non_leap_year() {
+		// 2100 is not a leap year despite being divisible by 4.
+		// 2100-03-01T00:00:00Z
+		assert_eq!(format_rfc3339_utc(4_107_542_400), "2100-03-01T00:00:00Z");
+	}
+
+	#[test]
+	fn year_boundary() {
+		// 2021-12-31T23:59:59Z -> 2022-01-01T00:00:00Z one second later
+		assert_eq!(format_rfc3339_utc(1_640_995_199), "2021-12-31T23:59:59Z");
+		assert_eq!(format_rfc3339_utc(1_640_995_200), "2022-01-01T00:00:00Z");
+	}
+}
+
+/// Detects the rustc release channel from a `rustc --version` string, e.g.
+/// `rustc 1.79.0-nightly (abcdef123 2024-05-01)` -> `nightly`.
+#[allow(dead_code)]
+fn rust_channel(rustc_version: &str) -> String {
+	let version = rustc_version
+		.split_whitespace()
+		.nth(1)
+		.unwrap_or(rustc_version);
+	if version.contains("-nightly") || version.contains("-dev") {
+		"nightly".to_string()
+	} else if version.contains("-beta") {
+		"beta".to_string()
+	} else {
+		"stable".to_string()
+	}
+}
+
+#[cfg(test)]
+mod rust_channel_tests {
+	use super::rust_channel;
+
+	#[test]
+	fn stable() {
+		assert_eq!(
+			rust_channel("rustc 1.79.0 (129f3b996 2024-06-10)"),
+			"stable"
+		);
+	}
+
+	#[test]
+	fn beta() {
+		assert_eq!(
+			rust_channel("rustc 1.80.0-beta.2 (f3ae2a805 2024-06-20)"),
+			"beta"
+		);
+	}
+
+	#[test]
+	fn nightly_suffix() {
+		assert_eq!(
+			rust_channel("rustc 1.81.0-nightly (a1b2c3d4e 2024-06-25)"),
+			"nightly"
+		);
+	}
+
+	#[test]
+	fn dev_suffix_treated_as_nightly() {
+		assert_eq!(rust_channel("rustc 1.81.0-dev"), "nightly");
+	}
+
+	#[test]
+	fn unparseable_string_falls_back_to_stable() {
+		assert_eq!(rust_channel("unknown"), "stable");
+	}
+}
@@ -3,12 +3,27 @@ use std::fmt;
 use std::fmt::{Display, Formatter};
 use std::string::String;
 
+// Shared with `build.rs` via `include!` so `format_rfc3339_utc`/`rust_channel` are unit-tested
+// as part of the crate instead of only living in the build script.
+#[path = "build_support.rs"]
+mod build_support;
+
 const BUILD_VERSION: &str = env!("MCPGW_BUILD_buildVersion");
 const BUILD_GIT_REVISION: &str = env!("MCPGW_BUILD_buildGitRevision");
 const BUILD_STATUS: &str = env!("MCPGW_BUILD_buildStatus");
 const BUILD_TAG: &str = env!("MCPGW_BUILD_buildTag");
 const BUILD_RUST_VERSION: &str = env!("MCPGW_BUILD_RUSTC_VERSION");
 const BUILD_RUST_PROFILE: &str = env!("MCPGW_BUILD_PROFILE_NAME");
+const BUILD_TIME: &str = env!("MCPGW_BUILD_TIMESTAMP");
+const BUILD_GIT_CLEAN: &str = env!("MCPGW_BUILD_GIT_CLEAN");
+const BUILD_GIT_BRANCH: &str = env!("MCPGW_BUILD_GIT_BRANCH");
+const BUILD_GIT_COMMIT_SHORT: &str = env!("MCPGW_BUILD_GIT_COMMIT_SHORT");
+const BUILD_FEATURES: &str = env!("MCPGW_BUILD_FEATURES");
+/// Captured `cargo tree` output, empty unless `MCPGW_CAPTURE_DEPENDENCIES=1` was set at build time.
+const BUILD_DEPENDENCIES: &str = include_str!(concat!(env!("OUT_DIR"), "/dependencies.txt"));
+const BUILD_TARGET: &str = env!("MCPGW_BUILD_TARGET");
+const BUILD_OS: &str = env!("MCPGW_BUILD_HOST");
+const BUILD_RUST_CHANNEL: &str = env!("MCPGW_BUILD_RUST_CHANNEL");
 
 #[derive(serde::Serialize, Clone, Debug, Default)]
 pub struct BuildInfo {
@@ -18,6 +33,23 @@ pub struct BuildInfo {
 	build_profile: String,
 	build_status: String,
 	git_tag: String,
+	/// RFC 3339 UTC instant the binary was built, honoring `SOURCE_DATE_EPOCH` when set.
+	build_time: String,
+	/// `YYYY-MM-DD` form of `build_time`, handy for log grepping without parsing RFC 3339.
+	build_date: String,
+	/// Whether `git status --porcelain` was empty at build time.
+	git_clean: bool,
+	git_branch: String,
+	git_commit_short: String,
+	features: Vec<String>,
+	/// `cargo tree` snapshot, only populated when capture was requested at build time.
+	dependencies: Option<String>,
+	/// Target triple the binary was compiled for, e.g. `x86_64-unknown-linux-gnu`.
+	build_target: String,
+	/// Host OS/arch the build ran on.
+	build_os: String,
+	/// `stable`, `beta`, or `nightly`, detected from the rustc version string.
+	rust_channel: String,
 }
 
 impl BuildInfo {
@@ -29,21 +61,112 @@ impl BuildInfo {
 			build_profile: BUILD_RUST_PROFILE.to_string(),
 			build_status: BUILD_STATUS.to_string(),
 			git_tag: BUILD_TAG.to_string(),
+			build_time: BUILD_TIME.to_string(),
+			build_date: BUILD_TIME
+				.split_once('T')
+				.map(|(date, _)| date.to_string())
+				.unwrap_or_else(|| BUILD_TIME.to_string()),
+			git_clean: BUILD_GIT_CLEAN == "true",
+			git_branch: BUILD_GIT_BRANCH.to_string(),
+			git_commit_short: BUILD_GIT_COMMIT_SHORT.to_string(),
+			features: if BUILD_FEATURES.is_empty() {
+				Vec::new()
+			} else {
+				BUILD_FEATURES.split(',').map(String::from).collect()
+			},
+			dependencies: if BUILD_DEPENDENCIES.is_empty() {
+				None
+			} else {
+				Some(BUILD_DEPENDENCIES.to_string())
+			},
+			build_target: BUILD_TARGET.to_string(),
+			build_os: BUILD_OS.to_string(),
+			rust_channel: BUILD_RUST_CHANNEL.to_string(),
 		}
 	}
 }
 
+impl BuildInfo {
+	/// Checks whether this build's `version` satisfies a caller-supplied semver requirement,
+	/// e.g. `">=1.2.0"` or `"^1.4"`. Lets clients negotiate compatibility at startup instead of
+	/// hard-coding version assumptions.
+	pub fn satisfies(&self, requirement: &str) -> Result<bool, semver::Error> {
+		let req = semver::VersionReq::parse(requirement)?;
+		let version = semver::Version::parse(&self.version)?;
+		Ok(req.matches(&version))
+	}
+}
+
+/// Renders `BuildInfo` as the JSON body for a `/version` diagnostic endpoint (or an MCP tool
+/// response), so build metadata can be fetched over the wire rather than only via `Display`.
+///
+/// This function only builds the JSON value. `crates/core` has no router or MCP tool registry
+/// of its own, and nothing in it registers this function as a `/version` route or MCP tool.
+pub fn version_handler() -> serde_json::Value {
+	serde_json::to_value(BuildInfo::new()).expect("BuildInfo always serializes")
+}
+
 impl Display for BuildInfo {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
 		write!(
 			f,
-			"version.BuildInfo{{RustVersion:\"{}\", BuildProfile:\"{}\", BuildStatus:\"{}\", GitTag:\"{}\", Version:\"{}\", GitRevision:\"{}\"}}",
+			"version.BuildInfo{{RustVersion:\"{}\", BuildProfile:\"{}\", BuildStatus:\"{}\", GitTag:\"{}\", Version:\"{}\", GitRevision:\"{}{}\", GitBranch:\"{}\", BuildTime:\"{}\", BuildDate:\"{}\", BuildTarget:\"{}\", BuildOS:\"{}\", RustChannel:\"{}\"}}",
 			self.rust_version,
 			self.build_profile,
 			self.build_status,
 			self.git_tag,
 			self.version,
-			self.git_revision
+			self.git_revision,
+			if self.git_clean { "" } else { "-dirty" },
+			self.git_branch,
+			self.build_time,
+			self.build_date,
+			self.build_target,
+			self.build_os,
+			self.rust_channel
 		)
 	}
 }
+
+#[cfg(test)]
+mod satisfies_tests {
+	use super::BuildInfo;
+
+	fn build_info_with_version(version: &str) -> BuildInfo {
+		BuildInfo {
+			version: version.to_string(),
+			..BuildInfo::default()
+		}
+	}
+
+	#[test]
+	fn matching_minimum_version() {
+		let info = build_info_with_version("1.4.2");
+		assert!(info.satisfies(">=1.2.0").unwrap());
+	}
+
+	#[test]
+	fn below_minimum_version() {
+		let info = build_info_with_version("1.1.0");
+		assert!(!info.satisfies(">=1.2.0").unwrap());
+	}
+
+	#[test]
+	fn caret_requirement() {
+		let info = build_info_with_version("1.4.9");
+		assert!(info.satisfies("^1.4").unwrap());
+		assert!(!info.satisfies("^1.5").unwrap());
+	}
+
+	#[test]
+	fn invalid_requirement_errors() {
+		let info = build_info_with_version("1.0.0");
+		assert!(info.satisfies("not-a-requirement").is_err());
+	}
+
+	#[test]
+	fn invalid_build_version_errors() {
+		let info = build_info_with_version("not-a-version");
+		assert!(info.satisfies(">=1.0.0").is_err());
+	}
+}